@@ -0,0 +1,147 @@
+use std::{
+    marker::PhantomData,
+    ops::Deref,
+    ptr::NonNull,
+    sync::atomic::{self, AtomicUsize, Ordering},
+};
+
+struct ArcInner<T> {
+    value: T,
+    references: AtomicUsize,
+}
+
+pub struct Arc<T> {
+    inner: NonNull<ArcInner<T>>,
+    // PhantomData needed here because Arc<T> is only connected to T through a
+    // raw pointer, which tells dropck nothing about ownership. This marker
+    // tells it that dropping an Arc<T> may drop a T, so borrows of T cannot
+    // outlive an Arc<T> that holds them.
+    // See https://youtu.be/8O0Nt9qY_vo?t=5870 and
+    // https://doc.rust-lang.org/nomicon/dropck.html for more info.
+    _marker: PhantomData<T>,
+}
+
+// SAFETY: Arc<T> can be sent across threads as long as T is both Send and
+// Sync: the inner allocation is reference counted atomically and the T is
+// dropped from whichever thread releases the last reference, while other
+// threads may still hold &T through their own clones.
+unsafe impl<T: Send + Sync> Send for Arc<T> {}
+// SAFETY: Arc<T> can be shared across threads as long as T is both Send and
+// Sync, for the same reason as above: a shared Arc can be cloned on another
+// thread, which then both reads the T and may be the one to drop it.
+unsafe impl<T: Send + Sync> Sync for Arc<T> {}
+
+impl<T> Arc<T> {
+    pub fn new(value: T) -> Self {
+        let inner = ArcInner {
+            value,
+            references: AtomicUsize::new(1),
+        };
+
+        Self {
+            // SAFETY: Box::new does not give a null pointer
+            // We need into_raw because otherwise the Box would
+            // be dropped at the end of the new() method.
+            inner: unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(inner))) },
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn ptr_eq(arc1: &Self, arc2: &Self) -> bool {
+        arc1.inner == arc2.inner
+    }
+}
+
+impl<T> Clone for Arc<T> {
+    fn clone(&self) -> Self {
+        let inner = unsafe { self.inner.as_ref() };
+        // Relaxed is fine here: a new count only matters for not freeing the
+        // allocation too early, and nothing else is synchronized by this
+        // increment.
+        inner.references.fetch_add(1, Ordering::Relaxed);
+
+        Self {
+            inner: self.inner,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Drop for Arc<T> {
+    fn drop(&mut self) {
+        let inner = unsafe { self.inner.as_ref() };
+
+        // Release ensures that any writes to the value made through this
+        // Arc happen-before the decrement is observed by whichever thread
+        // ends up freeing the allocation.
+        if inner.references.fetch_sub(1, Ordering::Release) != 1 {
+            return;
+        }
+
+        // Acquire fence: pairs with the Release above so that all prior
+        // decrements from other threads happen-before the deallocation
+        // below. Without it, another thread could still be reading the
+        // value while we free it.
+        atomic::fence(Ordering::Acquire);
+
+        // `inner`'s last use is above: NLL ends the borrow here, before the
+        // box is dropped. If code after this point tried to use `inner`, it
+        // would be invalid.
+        let _ = inner;
+
+        // SAFETY: we observed the last reference being dropped, so we can
+        //         safely dereference it and then drop it
+        let inner_box = unsafe { Box::from_raw(self.inner.as_ptr()) };
+        drop(inner_box);
+    }
+}
+
+impl<T> Deref for Arc<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: self.inner is a Box on the heap, that is only deallocated when the last Arc
+        //         that references it gets dropped. This Arc exists, therefore there should still
+        //         be a self.inner allocated.
+        unsafe { &self.inner.as_ref().value }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Arc;
+
+    #[test]
+    fn test() {
+        let x = Arc::new(5);
+        assert_eq!(*x, 5);
+
+        let y = Arc::clone(&x);
+        assert_eq!(*x, 5);
+        assert_eq!(*y, 5);
+
+        assert!(Arc::ptr_eq(&x, &y));
+    }
+
+    #[test]
+    #[allow(unused_assignments, unused_variables)]
+    fn test_drop_check() {
+        // y must be declared after x (and thus dropped before it), so that the
+        // Arc referencing x is gone before the String it points to is freed.
+        let (x, y);
+        x = String::from("foo");
+        y = Arc::new(&x);
+    }
+
+    #[test]
+    fn test_send_across_thread() {
+        let x = Arc::new(5);
+        let y = Arc::clone(&x);
+
+        let handle = std::thread::spawn(move || {
+            assert_eq!(*y, 5);
+        });
+
+        handle.join().unwrap();
+        assert_eq!(*x, 5);
+    }
+}
@@ -1,4 +1,4 @@
-use std::cell::UnsafeCell;
+use std::{cell::UnsafeCell, mem};
 
 #[derive(Debug)]
 pub struct Cell<T> {
@@ -17,14 +17,56 @@ impl<T> Cell<T> {
         unsafe { *self.value.get() = value};
     }
 
-    pub fn get(&self) -> T 
+    pub fn get(&self) -> T
     where
         T: Copy
     {
-        // SAFETY: this is safe because T implements copy, and will be copied. 
+        // SAFETY: this is safe because T implements copy, and will be copied.
         // SAFETY: this is not thread-safe, but that's okay because UnsafeCell is !Sync thus Cell is !Sync
         unsafe { *self.value.get() }
     }
+
+    pub fn replace(&self, value: T) -> T {
+        // SAFETY: this is safe because the old value is moved out via mem::replace
+        // rather than copied, so no aliasing reference to it is ever created.
+        // SAFETY: this is not thread-safe, but that's okay because UnsafeCell is !Sync thus Cell is !Sync
+        unsafe { mem::replace(&mut *self.value.get(), value) }
+    }
+
+    pub fn take(&self) -> T
+    where
+        T: Default
+    {
+        self.replace(T::default())
+    }
+
+    pub fn swap(&self, other: &Cell<T>) {
+        if std::ptr::eq(self, other) {
+            return;
+        }
+        // SAFETY: self and other are distinct cells (checked above), so the two raw
+        // pointers below never alias; ptr::swap operates through them directly rather
+        // than manufacturing aliasing &mut references into the UnsafeCells.
+        // SAFETY: this is not thread-safe, but that's okay because UnsafeCell is !Sync thus Cell is !Sync
+        unsafe { std::ptr::swap(self.value.get(), other.value.get()) };
+    }
+
+    pub fn update(&self, f: impl FnOnce(T) -> T)
+    where
+        T: Copy
+    {
+        self.set(f(self.get()));
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        // SAFETY: safe because &mut self statically proves we hold the only
+        // reference to this Cell, so there can be no other aliasing access.
+        self.value.get_mut()
+    }
 }
 
 #[cfg(test)]
@@ -39,4 +81,31 @@ mod test {
         x.set(20);
         assert_eq!(x.get(), 20);
     }
+
+    #[test]
+    fn test_replace_and_take() {
+        let x = Cell::new(String::from("foo"));
+        assert_eq!(x.replace(String::from("bar")), "foo");
+        assert_eq!(x.take(), "bar");
+        assert_eq!(x.take(), "");
+    }
+
+    #[test]
+    fn test_swap_and_update() {
+        let x = Cell::new(1);
+        let y = Cell::new(2);
+        x.swap(&y);
+        assert_eq!(x.get(), 2);
+        assert_eq!(y.get(), 1);
+
+        x.update(|v| v + 10);
+        assert_eq!(x.get(), 12);
+    }
+
+    #[test]
+    fn test_into_inner_and_get_mut() {
+        let mut x = Cell::new(5);
+        *x.get_mut() += 1;
+        assert_eq!(x.into_inner(), 6);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,6 @@
+pub mod cell;
+pub mod rc;
+pub mod refcell;
+pub mod arc;
+pub mod mutex;
+pub mod once_cell;
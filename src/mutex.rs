@@ -0,0 +1,110 @@
+use std::{
+    cell::UnsafeCell,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+const UNLOCKED: bool = false;
+const LOCKED: bool = true;
+
+pub struct Mutex<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: Mutex<T> can be shared across threads as long as T can be sent to
+// another thread, since the atomic `locked` flag guarantees only one thread
+// at a time ever gets access to the UnsafeCell.
+unsafe impl<T: Send> Sync for Mutex<T> {}
+
+impl<T> Mutex<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            locked: AtomicBool::new(UNLOCKED),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        while self
+            .locked
+            .compare_exchange_weak(UNLOCKED, LOCKED, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            // Spin with a relaxed load while contended, so we don't
+            // hammer the cache line with compare_exchange's write traffic.
+            while self.locked.load(Ordering::Relaxed) == LOCKED {
+                std::hint::spin_loop();
+            }
+        }
+
+        MutexGuard { mutex: self }
+    }
+}
+
+pub struct MutexGuard<'mutex, T> {
+    mutex: &'mutex Mutex<T>,
+}
+
+impl<T> Deref for MutexGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the very existence of this MutexGuard guarantees we have
+        //         exclusively locked the mutex, so no one else is reading or
+        //         writing the value.
+        unsafe { &*self.mutex.value.get() }
+    }
+}
+
+impl<T> DerefMut for MutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: the very existence of this MutexGuard guarantees we have
+        //         exclusively locked the mutex, so no one else is reading or
+        //         writing the value.
+        unsafe { &mut *self.mutex.value.get() }
+    }
+}
+
+impl<T> Drop for MutexGuard<'_, T> {
+    fn drop(&mut self) {
+        // Release pairs with the Acquire in lock(), so that writes made
+        // under this guard are visible to whichever thread acquires the
+        // lock next.
+        self.mutex.locked.store(UNLOCKED, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Mutex;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_single_threaded() {
+        let mutex = Mutex::new(0);
+        *mutex.lock() += 1;
+        assert_eq!(*mutex.lock(), 1);
+    }
+
+    #[test]
+    fn test_multi_threaded() {
+        let mutex = Arc::new(Mutex::new(0));
+
+        let handles: Vec<_> = (0..10)
+            .map(|_| {
+                let mutex = Arc::clone(&mutex);
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        *mutex.lock() += 1;
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(*mutex.lock(), 10_000);
+    }
+}
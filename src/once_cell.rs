@@ -0,0 +1,74 @@
+use std::cell::UnsafeCell;
+
+#[derive(Debug)]
+pub struct OnceCell<T> {
+    value: UnsafeCell<Option<T>>,
+}
+
+impl<T> OnceCell<T> {
+    pub fn new() -> Self {
+        Self {
+            value: UnsafeCell::new(None),
+        }
+    }
+
+    pub fn get(&self) -> Option<&T> {
+        // SAFETY: the value is only ever written once, by set/get_or_init,
+        //         and never overwritten afterwards, so a shared reference
+        //         into it can safely coexist with this one.
+        unsafe { &*self.value.get() }.as_ref()
+    }
+
+    pub fn set(&self, value: T) -> Result<(), T> {
+        if self.get().is_some() {
+            return Err(value);
+        }
+
+        // SAFETY: we just checked that the value is still empty, and
+        //         OnceCell is !Sync so no other thread could have raced us
+        //         to set it in the meantime.
+        unsafe { *self.value.get() = Some(value) };
+        Ok(())
+    }
+
+    pub fn get_or_init(&self, f: impl FnOnce() -> T) -> &T {
+        if self.get().is_none() {
+            // the value may have already been set by a reentrant call to f,
+            // in which case this silently keeps the first value and drops
+            // the one returned by this call.
+            let _ = self.set(f());
+        }
+
+        self.get().expect("value was just set above")
+    }
+}
+
+impl<T> Default for OnceCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::OnceCell;
+
+    #[test]
+    fn test_set_and_get() {
+        let cell = OnceCell::new();
+        assert_eq!(cell.get(), None);
+
+        assert_eq!(cell.set(5), Ok(()));
+        assert_eq!(cell.get(), Some(&5));
+
+        assert_eq!(cell.set(10), Err(10));
+        assert_eq!(cell.get(), Some(&5));
+    }
+
+    #[test]
+    fn test_get_or_init() {
+        let cell = OnceCell::new();
+        assert_eq!(cell.get_or_init(|| 5), &5);
+        assert_eq!(cell.get_or_init(|| panic!("should not be called again")), &5);
+    }
+}
@@ -1,46 +1,68 @@
-use std::{ops::Deref, ptr::NonNull};
+use std::{marker::PhantomData, mem::ManuallyDrop, ops::Deref, ptr::NonNull};
 use crate::cell::Cell;
 
 struct RcInner<T> {
-    value: T,
-    references: Cell<usize>,
+    value: ManuallyDrop<T>,
+    strong: Cell<usize>,
+    weak: Cell<usize>,
 }
 
 pub struct Rc<T> {
     inner: NonNull<RcInner<T>>,
-    /// TODO: PhantomData needed here because...
-    /// See https://youtu.be/8O0Nt9qY_vo?t=5870 and 
-    /// https://doc.rust-lang.org/nomicon/dropck.html for more info.
+    // PhantomData needed here because Rc<T> is only connected to T through a
+    // raw pointer, which tells dropck nothing about ownership. This marker
+    // tells it that dropping an Rc<T> may drop a T, so borrows of T cannot
+    // outlive an Rc<T> that holds them.
+    // See https://youtu.be/8O0Nt9qY_vo?t=5870 and
+    // https://doc.rust-lang.org/nomicon/dropck.html for more info.
+    _marker: PhantomData<T>,
 }
 
 impl<T> Rc<T> {
     pub fn new(value: T) -> Self {
         let inner = RcInner {
-            value,
-            references: Cell::new(1),
+            value: ManuallyDrop::new(value),
+            strong: Cell::new(1),
+            // the set of all Rcs collectively counts as one weak reference,
+            // so the allocation stays alive until the last Rc (which drops
+            // this implicit weak reference) AND the last Weak are both gone.
+            weak: Cell::new(1),
         };
 
         Self {
             // SAFETY: Box::new does not give a null pointer
-            // We need into_raw because otherwise the Box would 
+            // We need into_raw because otherwise the Box would
             // be dropped at the end of the new() method.
-            inner: unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(inner))) }
+            inner: unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(inner))) },
+            _marker: PhantomData,
         }
     }
 
     pub fn ptr_eq(rc1: &Self, rc2: &Self) -> bool {
         rc1.inner == rc2.inner
     }
+
+    pub fn downgrade(&self) -> Weak<T> {
+        let inner = unsafe { self.inner.as_ref() };
+        let n_weak = inner.weak.get();
+        inner.weak.set(n_weak + 1);
+
+        Weak {
+            inner: self.inner,
+            _marker: PhantomData,
+        }
+    }
 }
 
 impl<T> Clone for Rc<T> {
     fn clone(&self) -> Self {
         let inner = unsafe { self.inner.as_ref() };
-        let n_references = inner.references.get();
-        inner.references.set(n_references + 1);
+        let n_strong = inner.strong.get();
+        inner.strong.set(n_strong + 1);
 
         Self {
-            inner: self.inner
+            inner: self.inner,
+            _marker: PhantomData,
         }
     }
 }
@@ -48,21 +70,26 @@ impl<T> Clone for Rc<T> {
 impl<T> Drop for Rc<T> {
     fn drop(&mut self) {
         let inner = unsafe { self.inner.as_ref() };
-        let n_references = inner.references.get();
+        let n_strong = inner.strong.get();
 
-        if n_references == 1 {
-            // Being paranoid here, ensuring that this pointer to inner gets dropped before the 
-            // box is dropped. If code after this if block attempted to use this inner pointer,
-            // it would be invalid.
-            drop(inner);
+        if n_strong == 1 {
+            inner.strong.set(0);
 
-            // SAFETY: we hold the only reference to inner, so we can
-            //         safely dereference it and then drop it
-            let inner_box = unsafe { Box::from_raw(self.inner.as_ptr()) };
-            drop(inner_box);
+            // `inner`'s last use is above: NLL ends the borrow here, before we
+            // mutate through a raw pointer below. If code after this point
+            // tried to use `inner`, it would be invalid.
+            let _ = inner;
+
+            // SAFETY: this is the last strong reference, and no other Rc can
+            //         read the value after this, so it is safe to drop it in place.
+            unsafe { ManuallyDrop::drop(&mut (*self.inner.as_ptr()).value) };
+
+            // dropping the value also drops the implicit weak reference held
+            // collectively by all Rcs.
+            drop(Weak { inner: self.inner, _marker: PhantomData });
         } else {
             // SAFETY: there are other references to inner around, so don't drop inner
-            inner.references.set(n_references - 1);
+            inner.strong.set(n_strong - 1);
         }
     }
 }
@@ -71,12 +98,71 @@ impl<T> Deref for Rc<T> {
     type Target = T;
     fn deref(&self) -> &Self::Target {
         // SAFETY: self.inner is a Box on the heap, that is only deallocated when the last Rc
-        //         that references it gets dropped. This Rc exists, therefore there should still
-        //         be a self.inner allocated.
+        //         and the last Weak that reference it get dropped. This Rc exists, therefore
+        //         there should still be a self.inner allocated, and its value not yet dropped.
         unsafe { &self.inner.as_ref().value }
     }
 }
 
+pub struct Weak<T> {
+    inner: NonNull<RcInner<T>>,
+    // See the comment on Rc<T>'s _marker field.
+    _marker: PhantomData<T>,
+}
+
+impl<T> Weak<T> {
+    pub fn upgrade(&self) -> Option<Rc<T>> {
+        let inner = unsafe { self.inner.as_ref() };
+        let n_strong = inner.strong.get();
+
+        if n_strong == 0 {
+            return None;
+        }
+
+        inner.strong.set(n_strong + 1);
+        Some(Rc {
+            inner: self.inner,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<T> Clone for Weak<T> {
+    fn clone(&self) -> Self {
+        let inner = unsafe { self.inner.as_ref() };
+        let n_weak = inner.weak.get();
+        inner.weak.set(n_weak + 1);
+
+        Self {
+            inner: self.inner,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Drop for Weak<T> {
+    fn drop(&mut self) {
+        let inner = unsafe { self.inner.as_ref() };
+        let n_weak = inner.weak.get();
+
+        if n_weak == 1 {
+            // `inner`'s last use is above: NLL ends the borrow here, before the
+            // box is dropped. If code after this point tried to use `inner`,
+            // it would be invalid.
+            let _ = inner;
+
+            // SAFETY: we hold the only weak reference to inner, and strong
+            //         must already be 0 (the value was dropped in Rc::drop
+            //         before its implicit weak reference was released), so
+            //         we can safely deallocate the whole allocation.
+            let inner_box = unsafe { Box::from_raw(self.inner.as_ptr()) };
+            drop(inner_box);
+        } else {
+            inner.weak.set(n_weak - 1);
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::Rc;
@@ -94,9 +180,31 @@ mod test {
     }
 
     #[test]
+    #[allow(unused_assignments, unused_variables)]
     fn test_drop_check() {
-        let (y, x);
+        // y must be declared after x (and thus dropped before it), so that the
+        // Rc referencing x is gone before the String it points to is freed.
+        let (x, y);
         x = String::from("foo");
         y = Rc::new(&x);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_weak_upgrade() {
+        let x = Rc::new(5);
+        let weak = x.downgrade();
+
+        let upgraded = weak.upgrade().expect("value should still be alive");
+        assert_eq!(*upgraded, 5);
+    }
+
+    #[test]
+    fn test_weak_upgrade_after_drop() {
+        let x = Rc::new(5);
+        let weak = x.downgrade();
+
+        drop(x);
+
+        assert!(weak.upgrade().is_none());
+    }
+}
@@ -1,4 +1,4 @@
-use std::{ops::{DerefMut, Deref}, cell::UnsafeCell};
+use std::{error::Error, fmt, ops::{DerefMut, Deref}, cell::UnsafeCell};
 use crate::cell::Cell;
 
 #[derive(Copy, Clone, Debug)]
@@ -22,38 +22,83 @@ impl<T> RefCell<T> {
        }
    }
 
-   pub fn borrow(&self) -> Option<Ref<'_, T>> {
+   pub fn try_borrow(&self) -> Result<Ref<'_, T>, BorrowError> {
        match self.state.get() {
            RefState::Unused => {
                self.state.set(RefState::Shared(1));
-               Some(Ref {
+               Ok(Ref {
                    refcell: self
                })
            }
            RefState::Shared(n) => {
                self.state.set(RefState::Shared(n + 1));
-               Some(Ref {
+               Ok(Ref {
                    refcell: self
                })
            }
-           RefState::Exclusive => None,
+           RefState::Exclusive => Err(BorrowError { _private: () }),
        }
    }
 
-   pub fn borrow_mut(&self) -> Option<RefMut<'_, T>> {
+   pub fn try_borrow_mut(&self) -> Result<RefMut<'_, T>, BorrowMutError> {
        match self.state.get() {
            RefState::Unused => {
                self.state.set(RefState::Exclusive);
-               Some(RefMut {
+               Ok(RefMut {
                    refcell: self
                })
            }
-           RefState::Shared(_) => None,
-           RefState::Exclusive => None,
+           RefState::Shared(_) | RefState::Exclusive => Err(BorrowMutError { _private: () }),
        }
    }
+
+   pub fn borrow(&self) -> Ref<'_, T> {
+       self.try_borrow().expect("already mutably borrowed")
+   }
+
+   pub fn borrow_mut(&self) -> RefMut<'_, T> {
+       self.try_borrow_mut().expect("already borrowed")
+   }
+
+   pub fn get_mut(&mut self) -> &mut T {
+       // SAFETY: safe because &mut self statically proves there are no
+       // outstanding Ref/RefMut borrows, so state doesn't need updating.
+       self.value.get_mut()
+   }
+
+   pub fn into_inner(self) -> T {
+       self.value.into_inner()
+   }
+}
+
+/// An error returned by [`RefCell::try_borrow`].
+#[derive(Debug)]
+pub struct BorrowError {
+    _private: (),
+}
+
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "already mutably borrowed")
+    }
+}
+
+impl Error for BorrowError {}
+
+/// An error returned by [`RefCell::try_borrow_mut`].
+#[derive(Debug)]
+pub struct BorrowMutError {
+    _private: (),
 }
 
+impl fmt::Display for BorrowMutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "already borrowed")
+    }
+}
+
+impl Error for BorrowMutError {}
+
 pub struct Ref<'refcell, T> {
     refcell: &'refcell RefCell<T>
 }
@@ -134,24 +179,46 @@ mod test {
 
         // the borrow here is expected to be dropped immediately,
         // meaning that the subsequent borrow_mut should work.
-        assert_eq!(x.borrow().map(|v| *v), Some(20));
+        assert_eq!(*x.borrow(), 20);
 
-        match x.borrow_mut() {
-            Some(mut x_ref) => {
-                *x_ref = 30;
-            }
-            None => panic!("expected to be able to borrow mut")
-        };
+        {
+            let mut x_ref = x.borrow_mut();
+            *x_ref = 30;
+        }
 
-        let x_ref1 = x.borrow();
+        let x_ref1 = x.try_borrow().expect("nothing else is borrowing yet");
 
         // there is still a reference x_ref1 around which hasn't been dropped
         // therefore we cannot expect to be able to borrow mutably
-        assert!(x.borrow_mut().is_none()); 
+        assert!(x.try_borrow_mut().is_err());
 
         // after the borrow_mut to ensure it isn't dropped before
-        assert_eq!(x_ref1.map(|v| *v), Some(30));
+        assert_eq!(*x_ref1, 30);
         let x_ref2 = x.borrow();
-        assert_eq!(x_ref2.map(|v| *v), Some(30));
+        assert_eq!(*x_ref2, 30);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_try_borrow_mut_while_borrowed() {
+        let x = RefCell::new(5);
+        let _x_ref = x.borrow();
+
+        let err = x.try_borrow_mut().unwrap_err();
+        assert_eq!(err.to_string(), "already borrowed");
+    }
+
+    #[test]
+    #[should_panic(expected = "already borrowed")]
+    fn test_borrow_mut_panics_while_borrowed() {
+        let x = RefCell::new(5);
+        let _x_ref = x.borrow();
+        x.borrow_mut();
+    }
+
+    #[test]
+    fn test_get_mut_and_into_inner() {
+        let mut x = RefCell::new(5);
+        *x.get_mut() += 1;
+        assert_eq!(x.into_inner(), 6);
+    }
+}